@@ -0,0 +1,82 @@
+//! Re-emits a token stream as colored source, mirroring the
+//! classifier-over-lexer approach rustc uses for rustdoc code highlighting:
+//! the lexer already classifies every slice into a [`Symbol`], which is
+//! exactly the input a highlighter needs.
+
+use crate::lexer::{Symbol, Token};
+
+/// Output format for [`highlight`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Ansi,
+    Html,
+}
+
+fn ansi_code(key: Symbol) -> &'static str {
+    match key {
+        Symbol::Identifier => "36",                            // cyan
+        Symbol::Number | Symbol::Float => "33",                 // yellow
+        Symbol::String => "32",                                 // green
+        Symbol::Operator | Symbol::Assign => "35",               // magenta
+        Symbol::Ponctuation | Symbol::LParen | Symbol::RParen => "37", // white
+        Symbol::Error => "31",                                  // red
+    }
+}
+
+fn html_class(key: Symbol) -> &'static str {
+    match key {
+        Symbol::Identifier => "ident",
+        Symbol::Number | Symbol::Float => "number",
+        Symbol::String => "string",
+        Symbol::Operator | Symbol::Assign => "operator",
+        Symbol::Ponctuation | Symbol::LParen | Symbol::RParen => "punct",
+        Symbol::Error => "error",
+    }
+}
+
+fn html_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Re-emits `source` as colored text in the requested `format`, coloring
+/// each token's span and passing everything between spans (whitespace,
+/// indentation) through untouched, so the original layout survives.
+pub fn highlight(source: &str, tokens: &[Token], format: Format) -> String {
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = String::new();
+    let mut cursor = 0;
+
+    for token in tokens {
+        let (start, end) = token.span;
+
+        if start > cursor {
+            out.extend(chars[cursor..start].iter());
+        }
+
+        let text: String = chars[start..end].iter().collect();
+
+        match format {
+            Format::Ansi => {
+                out.push_str(&format!("\x1b[{}m{}\x1b[0m", ansi_code(token.key), text));
+            }
+            Format::Html => {
+                out.push_str(&format!(
+                    "<span class=\"{}\">{}</span>",
+                    html_class(token.key),
+                    html_escape(&text)
+                ));
+            }
+        }
+
+        cursor = end;
+    }
+
+    if cursor < chars.len() {
+        out.extend(chars[cursor..].iter());
+    }
+
+    out
+}
@@ -1,33 +1,157 @@
 use std::env;
 use std::error::Error;
+use std::fmt;
 use std::fs::File;
 use std::io::{prelude::*, BufReader};
 
+/// The position of a token (or a lexing error) in the original source.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Loc {
+    pub line: i32,
+    pub column: i32,
+}
+
+impl fmt::Display for Loc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "line {}, col {}", self.line, self.column)
+    }
+}
+
+/// A lexing failure, located at the slice that triggered it.
+#[derive(Debug, Clone)]
+pub struct LexError {
+    pub loc: Loc,
+    pub message: String,
+}
+
+impl fmt::Display for LexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[error] {}: {}", self.loc, self.message)
+    }
+}
+
+impl Error for LexError {}
+
+/// A seekable cursor over the source's characters.
+///
+/// Tracks `line`/`col` as it advances and keeps enough history (`history`,
+/// `line_lengths`) to restore them when [`Cursor::seek_back`] steps back
+/// across a line boundary. Working in `char`s (instead of raw bytes) means
+/// identifiers can use any Unicode letter and column counts stay correct
+/// across multibyte characters.
 #[derive(Debug)]
-pub struct Cursor {
+struct Cursor {
+    chars: Vec<char>,
+    pos: usize,
+    history: Vec<char>,
+    line_lengths: Vec<i32>,
     line: i32,
-    column: i32,
+    col: i32,
+}
+
+impl Cursor {
+    fn new(src: &str) -> Cursor {
+        Cursor {
+            chars: src.chars().collect(),
+            pos: 0,
+            history: vec![],
+            line_lengths: vec![],
+            line: 1,
+            col: 0,
+        }
+    }
+
+    fn loc(&self) -> Loc {
+        Loc {
+            line: self.line,
+            column: self.col,
+        }
+    }
+
+    /// Looks at the next character without consuming it.
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    /// The char offset of the next character to be consumed, usable as a
+    /// span endpoint for slicing the original source.
+    fn pos(&self) -> usize {
+        self.pos
+    }
+
+    /// Consumes and returns the next character, updating `line`/`col`.
+    fn advance(&mut self) -> Option<char> {
+        let ch = *self.chars.get(self.pos)?;
+
+        self.pos += 1;
+        self.history.push(ch);
+
+        if ch == '\n' {
+            self.line_lengths.push(self.col);
+            self.line += 1;
+            self.col = 0;
+        } else {
+            self.col += 1;
+        }
+
+        Some(ch)
+    }
+
+    /// Un-consumes the last character returned by [`Cursor::advance`],
+    /// restoring `line`/`col` (using `line_lengths` to recover the column
+    /// when backtracking across a line boundary).
+    fn seek_back(&mut self) {
+        let ch = match self.history.pop() {
+            Some(ch) => ch,
+            None => return,
+        };
+
+        self.pos -= 1;
+
+        if ch == '\n' {
+            self.line -= 1;
+            self.col = self.line_lengths.pop().unwrap_or(0);
+        } else {
+            self.col -= 1;
+        }
+    }
 }
 
 #[derive(Debug)]
 pub struct Lexer {
-    cursor: Cursor,
     tokens: Vec<Token>,
+    source: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Copy)]
 pub enum Symbol {
     Identifier,
+    /// An integer literal: plain decimal, or `0x`/`0b`/`0o` prefixed.
     Number,
+    /// A literal with a fractional part and/or exponent, e.g. `3.14`, `1e-9`.
+    Float,
+    /// A `"..."` literal; `Token::value` holds the decoded contents.
+    String,
     Ponctuation,
     Operator,
     Assign,
+    LParen,
+    RParen,
+    /// A slice that could not be classified; carried instead of aborting the
+    /// scan so the rest of the source still gets lexed.
+    Error,
 }
 
 #[derive(Debug, Clone)]
 pub struct Token {
     pub key: Symbol,
     pub value: String,
+    pub loc: Loc,
+    /// Char offsets `[start, end)` of the token's raw slice in the original
+    /// source, e.g. for a highlighter that needs to re-emit exact source
+    /// text rather than `value` (which, for `Symbol::String`, holds the
+    /// *decoded* contents).
+    pub span: (usize, usize),
 }
 
 impl Token {
@@ -39,63 +163,290 @@ impl Token {
 impl Lexer {
     pub fn new() -> Lexer {
         Lexer {
-            cursor: Cursor { line: 1, column: 1 },
             tokens: vec![],
+            source: String::new(),
         }
     }
 
-    fn is_ponctuation(ch: u8) -> bool {
-        ch == 0x3b
+    fn is_ponctuation(ch: char) -> bool {
+        ch == ';'
     }
 
-    fn is_digit(ch: u8) -> bool {
-        matches!(ch, 0x30..=0x39)
+    fn is_digit(ch: char) -> bool {
+        ch.is_ascii_digit()
     }
 
-    fn is_letter(ch: u8) -> bool {
-        matches!(ch, 0x41..=0x5a | 0x61..=0x7a)
+    fn is_letter(ch: char) -> bool {
+        ch.is_alphabetic()
     }
 
-    fn is_operator(ch: u8) -> bool {
-        matches!(ch, 0x21 | 0x3c..=0x3e)
+    fn is_alnum(ch: char) -> bool {
+        ch.is_alphanumeric()
     }
 
-    fn is_new_line(ch: u8) -> bool {
-        ch == 0x0a
+    fn is_operator(ch: char) -> bool {
+        matches!(ch, '!' | '<'..='>')
     }
 
-    fn is_whitespace_like(ch: u8) -> bool {
-        matches!(ch, 0x09 | 0x0c | 0x0d | 0x20)
+    fn is_arith_operator(ch: char) -> bool {
+        matches!(ch, '*' | '+' | '-' | '/')
     }
 
-    fn refresh_cursor(&mut self, token_value: &mut String, ch: u8) {
-        if !Lexer::is_whitespace_like(ch) && !Lexer::is_new_line(ch) {
-            self.cursor = Cursor {
-                column: self.cursor.column + 1,
-                ..self.cursor
-            };
+    fn is_lparen(ch: char) -> bool {
+        ch == '('
+    }
 
-            token_value.push(char::from_u32(ch as u32).unwrap());
-        } else if Lexer::is_new_line(ch) {
-            self.cursor = Cursor {
-                line: self.cursor.line + 1,
-                column: 0,
-            };
-        }
+    fn is_rparen(ch: char) -> bool {
+        ch == ')'
+    }
+
+    fn is_new_line(ch: char) -> bool {
+        ch == '\n'
+    }
+
+    fn is_whitespace_like(ch: char) -> bool {
+        matches!(ch, '\t' | '\x0c' | '\r' | ' ')
     }
 
     pub fn advance(&self, pos: usize) -> Option<&Token> {
         self.tokens.get(pos)
     }
 
-    fn nchar(&mut self, buffer: Vec<u8>) -> Result<(), String> {
+    /// The full token stream produced by the last scan, e.g. for a
+    /// highlighter that needs every token rather than one at a time.
+    pub fn tokens(&self) -> &[Token] {
+        &self.tokens
+    }
+
+    /// The raw source text produced by the last scan, e.g. for a highlighter
+    /// that needs to slice a token's [`Token::span`] out of the original
+    /// text rather than reconstructing it from `value`.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    // scans a full number literal starting at the cursor's current position
+    // (whose first digit has *not* been consumed yet), recognizing the
+    // `0x`/`0b`/`0o` integer prefixes plus a float's fractional part and
+    // exponent
+    fn scan_number(
+        cursor: &mut Cursor,
+        token_start: Loc,
+        token_start_pos: usize,
+    ) -> Result<Token, LexError> {
+        let mut value = String::new();
+        let mut is_float = false;
+
+        value.push(cursor.advance().unwrap());
+
+        if value == "0" && matches!(cursor.peek(), Some('x' | 'X' | 'b' | 'B' | 'o' | 'O')) {
+            let base = cursor.peek().unwrap();
+
+            value.push(cursor.advance().unwrap());
+
+            loop {
+                let is_base_digit = match base {
+                    'x' | 'X' => matches!(cursor.peek(), Some(c) if c.is_ascii_hexdigit()),
+                    'b' | 'B' => matches!(cursor.peek(), Some('0' | '1')),
+                    _ => matches!(cursor.peek(), Some('0'..='7')),
+                };
+
+                if !is_base_digit {
+                    break;
+                }
+
+                value.push(cursor.advance().unwrap());
+            }
+
+            return Lexer::finish_number(cursor, token_start, token_start_pos, value, false);
+        }
+
+        while matches!(cursor.peek(), Some(c) if Lexer::is_digit(c)) {
+            value.push(cursor.advance().unwrap());
+        }
+
+        if cursor.peek() == Some('.') {
+            is_float = true;
+            value.push(cursor.advance().unwrap());
+
+            while matches!(cursor.peek(), Some(c) if Lexer::is_digit(c)) {
+                value.push(cursor.advance().unwrap());
+            }
+        }
+
+        if matches!(cursor.peek(), Some('e') | Some('E')) {
+            is_float = true;
+            value.push(cursor.advance().unwrap());
+
+            if matches!(cursor.peek(), Some('+') | Some('-')) {
+                value.push(cursor.advance().unwrap());
+            }
+
+            while matches!(cursor.peek(), Some(c) if Lexer::is_digit(c)) {
+                value.push(cursor.advance().unwrap());
+            }
+        }
+
+        Lexer::finish_number(cursor, token_start, token_start_pos, value, is_float)
+    }
+
+    // a digit run directly followed by a letter (`123abc`, `0x1g`) is not a
+    // valid suffix on any numeric literal this lexer recognizes; consume the
+    // offending run too so scanning resumes past it, and report it instead
+    // of silently splitting into a number token plus an identifier token
+    fn finish_number(
+        cursor: &mut Cursor,
+        token_start: Loc,
+        token_start_pos: usize,
+        mut value: String,
+        is_float: bool,
+    ) -> Result<Token, LexError> {
+        if matches!(cursor.peek(), Some(c) if Lexer::is_letter(c)) {
+            while matches!(cursor.peek(), Some(c) if Lexer::is_alnum(c)) {
+                value.push(cursor.advance().unwrap());
+            }
+
+            return Err(LexError {
+                loc: token_start,
+                message: "malformed number literal".to_string(),
+            });
+        }
+
+        Ok(Token {
+            key: if is_float { Symbol::Float } else { Symbol::Number },
+            value,
+            loc: token_start,
+            span: (token_start_pos, cursor.pos()),
+        })
+    }
+
+    // scans a `"..."` literal; the opening quote has already been consumed.
+    // decodes `\n`, `\t`, `\\`, `\"` and `\u{..}` escapes
+    fn scan_string(
+        cursor: &mut Cursor,
+        token_start: Loc,
+        token_start_pos: usize,
+    ) -> Result<Token, LexError> {
+        let mut value = String::new();
+
+        loop {
+            match cursor.advance() {
+                None => {
+                    return Err(LexError {
+                        loc: token_start,
+                        message: "unterminated string literal".to_string(),
+                    })
+                }
+                Some('"') => {
+                    return Ok(Token {
+                        key: Symbol::String,
+                        value,
+                        loc: token_start,
+                        span: (token_start_pos, cursor.pos()),
+                    })
+                }
+                Some('\\') => match cursor.advance() {
+                    Some('n') => value.push('\n'),
+                    Some('t') => value.push('\t'),
+                    Some('\\') => value.push('\\'),
+                    Some('"') => value.push('"'),
+                    Some('u') if cursor.peek() == Some('{') => {
+                        cursor.advance();
+
+                        let mut code = String::new();
+
+                        while matches!(cursor.peek(), Some(c) if c != '}') {
+                            code.push(cursor.advance().unwrap());
+                        }
+
+                        cursor.advance();
+
+                        if let Some(decoded) =
+                            u32::from_str_radix(&code, 16).ok().and_then(char::from_u32)
+                        {
+                            value.push(decoded);
+                        }
+                    }
+                    Some(other) => value.push(other),
+                    None => {
+                        return Err(LexError {
+                            loc: token_start,
+                            message: "unterminated string literal".to_string(),
+                        })
+                    }
+                },
+                Some(ch) => value.push(ch),
+            }
+        }
+    }
+
+    // never aborts on a bad character: unrecognized input becomes a
+    // `Symbol::Error` token carrying a diagnostic, and scanning resumes
+    // right after it, so a single pass can report every lexical error
+    fn nchar(&mut self, src: &str) -> Vec<LexError> {
+        let mut cursor = Cursor::new(src);
         // NOTE: state is an information storage (automata theory)
         let mut state = 0;
         let mut tokens = vec![];
+        let mut diagnostics = vec![];
         let mut value = String::new();
+        let mut token_start = cursor.loc();
+        let mut token_start_pos = cursor.pos();
+
+        while let Some(ch) = cursor.peek() {
+            if state == 0 && value.is_empty() {
+                token_start = cursor.loc();
+                token_start_pos = cursor.pos();
+
+                if Lexer::is_digit(ch) {
+                    match Lexer::scan_number(&mut cursor, token_start, token_start_pos) {
+                        Ok(token) => tokens.push(token),
+                        Err(err) => {
+                            tokens.push(Token {
+                                key: Symbol::Error,
+                                value: String::new(),
+                                loc: token_start,
+                                span: (token_start_pos, cursor.pos()),
+                            });
+                            diagnostics.push(err);
+                        }
+                    }
+
+                    continue;
+                }
+
+                if ch == '"' {
+                    cursor.advance();
+
+                    match Lexer::scan_string(&mut cursor, token_start, token_start_pos) {
+                        Ok(token) => tokens.push(token),
+                        Err(err) => {
+                            tokens.push(Token {
+                                key: Symbol::Error,
+                                value: String::new(),
+                                loc: token_start,
+                                span: (token_start_pos, cursor.pos()),
+                            });
+                            diagnostics.push(err);
+                        }
+                    }
+
+                    continue;
+                }
+            }
+
+            if value.is_empty() {
+                token_start = cursor.loc();
+                token_start_pos = cursor.pos();
+            }
 
-        for &ch in buffer.iter() {
-            Lexer::refresh_cursor(self, &mut value, ch);
+            cursor.advance();
+
+            let appended = !Lexer::is_whitespace_like(ch) && !Lexer::is_new_line(ch);
+
+            if appended {
+                value.push(ch);
+            }
 
             match state {
                 0 => {
@@ -103,35 +454,34 @@ impl Lexer {
                         state = 0;
                     } else if Lexer::is_letter(ch) {
                         state = 1;
-                    } else if Lexer::is_digit(ch) {
-                        state = 3;
                     } else if Lexer::is_operator(ch) {
                         state = 5;
-                    } else if Lexer::is_ponctuation(ch) {
+                    } else if Lexer::is_arith_operator(ch) {
                         tokens.push(Token {
-                            key: Symbol::Ponctuation,
+                            key: Symbol::Operator,
+                            span: (token_start_pos, token_start_pos + value.chars().count()),
                             value: value.clone(),
+                            loc: token_start,
                         });
 
                         state = 0;
                         value = String::new();
-                    } else {
-                        println!(
-                            "DEBUG: line: {} column: {}",
-                            self.cursor.line, self.cursor.column
-                        );
+                    } else if Lexer::is_lparen(ch) {
+                        tokens.push(Token {
+                            key: Symbol::LParen,
+                            span: (token_start_pos, token_start_pos + value.chars().count()),
+                            value: value.clone(),
+                            loc: token_start,
+                        });
 
-                        return Err("Lexer: Unrecognized symbol".to_string());
-                    }
-                }
-                1 => {
-                    if Lexer::is_letter(ch) || Lexer::is_digit(ch) {
-                        state = 1;
-                    } else if Lexer::is_whitespace_like(ch) || Lexer::is_operator(ch) {
-                        // NOTE: state == 2
+                        state = 0;
+                        value = String::new();
+                    } else if Lexer::is_rparen(ch) {
                         tokens.push(Token {
-                            key: Symbol::Identifier,
+                            key: Symbol::RParen,
+                            span: (token_start_pos, token_start_pos + value.chars().count()),
                             value: value.clone(),
+                            loc: token_start,
                         });
 
                         state = 0;
@@ -139,28 +489,45 @@ impl Lexer {
                     } else if Lexer::is_ponctuation(ch) {
                         tokens.push(Token {
                             key: Symbol::Ponctuation,
+                            span: (token_start_pos, token_start_pos + value.chars().count()),
                             value: value.clone(),
+                            loc: token_start,
                         });
 
                         state = 0;
                         value = String::new();
                     } else {
-                        println!(
-                            "DEBUG: line: {} column: {}",
-                            self.cursor.line, self.cursor.column
-                        );
+                        diagnostics.push(LexError {
+                            loc: token_start,
+                            message: "unrecognized symbol".to_string(),
+                        });
+                        tokens.push(Token {
+                            key: Symbol::Error,
+                            span: (token_start_pos, token_start_pos + value.chars().count()),
+                            value: value.clone(),
+                            loc: token_start,
+                        });
 
-                        return Err("Lexer: Malformed identifier".to_string());
+                        state = 0;
+                        value = String::new();
                     }
                 }
-                3 => {
-                    if Lexer::is_digit(ch) {
-                        state = 3;
-                    } else if !Lexer::is_letter(ch) {
-                        // NOTE: state == 4
+                1 => {
+                    if Lexer::is_alnum(ch) {
+                        state = 1;
+                    } else if Lexer::is_whitespace_like(ch)
+                        || Lexer::is_new_line(ch)
+                        || Lexer::is_operator(ch)
+                        || Lexer::is_arith_operator(ch)
+                        || Lexer::is_lparen(ch)
+                        || Lexer::is_rparen(ch)
+                    {
+                        // NOTE: state == 2
                         tokens.push(Token {
-                            key: Symbol::Number,
+                            key: Symbol::Identifier,
+                            span: (token_start_pos, token_start_pos + value.chars().count()),
                             value: value.clone(),
+                            loc: token_start,
                         });
 
                         state = 0;
@@ -168,18 +535,27 @@ impl Lexer {
                     } else if Lexer::is_ponctuation(ch) {
                         tokens.push(Token {
                             key: Symbol::Ponctuation,
+                            span: (token_start_pos, token_start_pos + value.chars().count()),
                             value: value.clone(),
+                            loc: token_start,
                         });
 
                         state = 0;
                         value = String::new();
                     } else {
-                        println!(
-                            "DEBUG: line: {} column: {}",
-                            self.cursor.line, self.cursor.column
-                        );
+                        diagnostics.push(LexError {
+                            loc: token_start,
+                            message: "malformed identifier".to_string(),
+                        });
+                        tokens.push(Token {
+                            key: Symbol::Error,
+                            span: (token_start_pos, token_start_pos + value.chars().count()),
+                            value: value.clone(),
+                            loc: token_start,
+                        });
 
-                        return Err("Lexer: Unrecognized number".to_string());
+                        state = 0;
+                        value = String::new();
                     }
                 }
                 5 => {
@@ -187,16 +563,28 @@ impl Lexer {
                         // NOTE: state == 6
                         tokens.push(Token {
                             key: Symbol::Operator,
+                            span: (token_start_pos, token_start_pos + value.chars().count()),
                             value: value.clone(),
+                            loc: token_start,
                         });
 
                         state = 0;
                         value = String::new();
                     } else if !Lexer::is_operator(ch) {
-                        // NOTE: state == 7
+                        // NOTE: state == 7; `ch` doesn't belong to this
+                        // single-char operator, so retract it and let the
+                        // next iteration rescan it as the start of a new
+                        // token, instead of swallowing it into `value`
+                        if appended {
+                            cursor.seek_back();
+                            value.pop();
+                        }
+
                         tokens.push(Token {
                             key: Symbol::Assign,
+                            span: (token_start_pos, token_start_pos + value.chars().count()),
                             value: value.clone(),
+                            loc: token_start,
                         });
 
                         state = 0;
@@ -204,7 +592,9 @@ impl Lexer {
                     } else if Lexer::is_ponctuation(ch) {
                         tokens.push(Token {
                             key: Symbol::Ponctuation,
+                            span: (token_start_pos, token_start_pos + value.chars().count()),
                             value: value.clone(),
+                            loc: token_start,
                         });
 
                         state = 0;
@@ -212,36 +602,128 @@ impl Lexer {
                     }
                 }
                 _ => {
-                    return Err("Lexer: Invalid state".to_string());
+                    diagnostics.push(LexError {
+                        loc: token_start,
+                        message: "invalid state".to_string(),
+                    });
+                    tokens.push(Token {
+                        key: Symbol::Error,
+                        span: (token_start_pos, token_start_pos + value.chars().count()),
+                        value: value.clone(),
+                        loc: token_start,
+                    });
+
+                    state = 0;
+                    value = String::new();
                 }
             }
         }
 
+        // flush whatever token was still open when the source ran out
+        if !value.is_empty() {
+            let key = match state {
+                1 => Symbol::Identifier,
+                // a trailing single-char relational operator, e.g. `<` with
+                // nothing after it: treat it like the state == 7 case above
+                5 => Symbol::Assign,
+                _ => Symbol::Error,
+            };
+            let span = (token_start_pos, token_start_pos + value.chars().count());
+
+            tokens.push(Token {
+                key,
+                value,
+                loc: token_start,
+                span,
+            });
+        }
+
         self.tokens = tokens;
+        self.source = src.to_string();
 
         // NOTE: `DEBUG=1 cargo run` to activate token printing
         if let Ok(_) = env::var("DEBUG") {
             println!("{:#?}", self.tokens);
         }
 
-        Ok(())
+        diagnostics
     }
 
-    pub fn scanner(&mut self, filepath: &str) -> Result<(), Box<dyn Error>> {
+    /// Scans `filepath` and returns every lexical diagnostic collected along
+    /// the way; the resulting tokens are stored on `self` and reachable via
+    /// [`Lexer::advance`]. Only I/O failures short-circuit with `Err`.
+    pub fn scanner(&mut self, filepath: &str) -> Result<Vec<LexError>, Box<dyn Error>> {
         let f = File::open(filepath)?;
         let mut reader = BufReader::new(f);
-        let mut buffer = Vec::new();
+        let mut src = String::new();
 
-        reader.read_to_end(&mut buffer)?;
+        reader.read_to_string(&mut src)?;
 
-        self.nchar(buffer)?;
+        Ok(self.nchar(&src))
+    }
 
-        Ok(())
+    /// Scans `src` directly, without touching the filesystem. Used by the
+    /// REPL, where each line arrives as a string rather than a file path.
+    pub fn scanner_str(&mut self, src: &str) -> Vec<LexError> {
+        self.nchar(src)
     }
 }
 
 #[cfg(test)]
 mod test {
+    use super::*;
+
     #[test]
     fn test_nchar() {}
+
+    #[test]
+    fn cursor_peek_and_advance() {
+        let mut cursor = Cursor::new("ab");
+
+        assert_eq!(cursor.peek(), Some('a'));
+        assert_eq!(cursor.advance(), Some('a'));
+        assert_eq!(cursor.peek(), Some('b'));
+        assert_eq!(cursor.advance(), Some('b'));
+        assert_eq!(cursor.advance(), None);
+    }
+
+    #[test]
+    fn cursor_seek_back_restores_the_last_char() {
+        let mut cursor = Cursor::new("ab");
+
+        cursor.advance();
+        cursor.advance();
+        cursor.seek_back();
+
+        assert_eq!(cursor.peek(), Some('b'));
+        assert_eq!(cursor.loc(), Loc { line: 1, column: 1 });
+    }
+
+    #[test]
+    fn cursor_seek_back_restores_column_across_a_newline() {
+        let mut cursor = Cursor::new("ab\nc");
+
+        cursor.advance(); // 'a', col 1
+        cursor.advance(); // 'b', col 2
+        cursor.advance(); // '\n', line 2, col 0
+        assert_eq!(cursor.loc(), Loc { line: 2, column: 0 });
+
+        cursor.seek_back();
+
+        assert_eq!(cursor.loc(), Loc { line: 1, column: 2 });
+        assert_eq!(cursor.peek(), Some('\n'));
+    }
+
+    #[test]
+    fn identifier_terminates_on_an_embedded_newline() {
+        let mut lexer = Lexer::new();
+        let diagnostics = lexer.scanner_str("foo\nbar");
+
+        assert!(diagnostics.is_empty());
+        assert_eq!(lexer.tokens().len(), 2);
+        assert_eq!(lexer.tokens()[0].key, Symbol::Identifier);
+        assert_eq!(lexer.tokens()[0].value, "foo");
+        assert_eq!(lexer.tokens()[1].key, Symbol::Identifier);
+        assert_eq!(lexer.tokens()[1].value, "bar");
+    }
 }
@@ -1,19 +1,98 @@
+pub mod highlight;
 pub mod lexer;
 pub mod parser;
 
+use std::env;
 use std::error::Error;
+use std::io::{self, BufRead, Write};
 
+use highlight::Format;
 use lexer::Lexer;
 use parser::Parser;
 
 fn main() -> Result<(), Box<dyn Error>> {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let highlight = take_highlight_flag(&mut args);
+
+    match args.into_iter().next() {
+        Some(path) => run_file(&path, highlight),
+        None => run_repl(highlight),
+    }
+}
+
+// removes `--highlight` (defaulting to ANSI) or `--highlight=<ansi|html>`
+// from `args` if present, reporting which format was requested
+fn take_highlight_flag(args: &mut Vec<String>) -> Option<Format> {
+    let pos = args
+        .iter()
+        .position(|arg| arg == "--highlight" || arg.starts_with("--highlight="))?;
+    let flag = args.remove(pos);
+
+    match flag.split_once('=') {
+        Some((_, "html")) => Some(Format::Html),
+        Some((_, format)) => {
+            if format != "ansi" {
+                eprintln!("unknown --highlight format '{}', using ansi", format);
+            }
+
+            Some(Format::Ansi)
+        }
+        None => Some(Format::Ansi),
+    }
+}
+
+fn run_file(path: &str, highlight: Option<Format>) -> Result<(), Box<dyn Error>> {
     let mut lexer = Lexer::new();
 
-    if let Err(err) = lexer.scanner("_input") {
-        eprintln!("{}", err);
-    };
+    match lexer.scanner(path) {
+        Err(err) => eprintln!("{}", err),
+        Ok(diagnostics) => {
+            for diagnostic in diagnostics {
+                eprintln!("{}", diagnostic);
+            }
+
+            report(&lexer, highlight);
+        }
+    }
+
+    Ok(())
+}
+
+// read a line from stdin, lex + parse it, print the result, repeat until EOF
+fn run_repl(highlight: Option<Format>) -> Result<(), Box<dyn Error>> {
+    let stdin = io::stdin();
 
-    let mut parser = Parser::new(&mut lexer);
+    print!("> ");
+    io::stdout().flush()?;
+
+    for line in stdin.lock().lines() {
+        let mut lexer = Lexer::new();
+
+        for diagnostic in lexer.scanner_str(&line?) {
+            eprintln!("{}", diagnostic);
+        }
+
+        report(&lexer, highlight);
+
+        print!("> ");
+        io::stdout().flush()?;
+    }
+
+    println!();
+
+    Ok(())
+}
+
+fn report(lexer: &Lexer, highlight: Option<Format>) {
+    if let Some(format) = highlight {
+        println!(
+            "{}",
+            crate::highlight::highlight(lexer.source(), lexer.tokens(), format)
+        );
+        return;
+    }
+
+    let mut parser = Parser::new(lexer);
 
     // call to the init token of the syntax
     match parser.e() {
@@ -21,8 +100,6 @@ fn main() -> Result<(), Box<dyn Error>> {
             "Parser: syntatic analysis error\n\n== DETAILS ==\n\n{}",
             err
         ),
-        Ok(_) => println!("Parsing Success"),
+        Ok(expr) => println!("{} = {}", expr, expr.eval()),
     }
-
-    Ok(())
 }
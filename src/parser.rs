@@ -2,22 +2,163 @@
 //! ------------
 //!
 //! ```
-//! E -> T E'
-//! E' -> OP T E' | &
-//! T -> id | num
-//! OP -> + | - | * | /
+//! E -> E ('+' | '-') E | E ('*' | '/') E | ('+' | '-') E | '(' E ')' | id | num | str
 //! ```
+//!
+//! Parsed via precedence climbing (Pratt parsing) instead of a recursive
+//! descent over the flat grammar above: `+`/`-` bind at power 10, `*`/`/`
+//! bind at power 20, so `2 + 3 * 4` parses as `2 + (3 * 4)`, and a
+//! parenthesized subexpression resets the binding power to 0.
+
+use std::error;
+use std::fmt;
 
 use crate::{
-    lexer::{Symbol, Token},
+    lexer::{Loc, Symbol, Token},
     Lexer,
 };
 
+/// A parsing failure, located at the token that triggered it.
+#[derive(Debug, Clone)]
+pub struct ParseError {
+    pub loc: Loc,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[error] {}: {}", self.loc, self.message)
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// An arithmetic operator, used by binary expressions and (for `+`/`-`) by
+/// prefix expressions.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Op {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Op {
+    fn from_token(token: &Token) -> Result<Op, ParseError> {
+        match token.value.as_str() {
+            "+" => Ok(Op::Add),
+            "-" => Ok(Op::Sub),
+            "*" => Ok(Op::Mul),
+            "/" => Ok(Op::Div),
+            _ => Err(ParseError {
+                loc: token.loc,
+                message: format!("token Operator expected, received: {:#?}", token),
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Op {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "*",
+            Op::Div => "/",
+        };
+
+        write!(f, "{}", symbol)
+    }
+}
+
+/// A node of the parse tree produced by [`Parser::e`].
+#[derive(Debug, Clone)]
+pub enum Expr {
+    Number(f64),
+    Ident(String),
+    Str(String),
+    BinOp {
+        op: Op,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+    },
+    /// A prefix `+`/`-` applied to the atom that follows, e.g. `-5`.
+    UnaryOp {
+        op: Op,
+        expr: Box<Expr>,
+    },
+}
+
+impl Expr {
+    /// Walks the tree and reduces it to a single number.
+    ///
+    /// Bare identifiers and string literals evaluate to `0.0` since this
+    /// crate has no notion of variable bindings or a string value domain yet.
+    pub fn eval(&self) -> f64 {
+        match self {
+            Expr::Number(n) => *n,
+            Expr::Ident(_) => 0.0,
+            Expr::Str(_) => 0.0,
+            Expr::BinOp { op, lhs, rhs } => {
+                let lhs = lhs.eval();
+                let rhs = rhs.eval();
+
+                match op {
+                    Op::Add => lhs + rhs,
+                    Op::Sub => lhs - rhs,
+                    Op::Mul => lhs * rhs,
+                    Op::Div => lhs / rhs,
+                }
+            }
+            Expr::UnaryOp { op, expr } => {
+                let value = expr.eval();
+
+                match op {
+                    Op::Add => value,
+                    Op::Sub => -value,
+                    // atom() only ever builds a UnaryOp from a `+` or `-` token
+                    Op::Mul | Op::Div => unreachable!("no unary * or /"),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Expr::Number(n) => write!(f, "{}", n),
+            Expr::Ident(name) => write!(f, "{}", name),
+            Expr::Str(value) => write!(f, "{:?}", value),
+            Expr::BinOp { op, lhs, rhs } => write!(f, "({} {} {})", lhs, op, rhs),
+            Expr::UnaryOp { op, expr } => write!(f, "({}{})", op, expr),
+        }
+    }
+}
+
+// parses a `Symbol::Number` value, which may carry a `0x`/`0b`/`0o` radix
+// prefix in addition to plain decimal digits
+fn parse_number(value: &str) -> Option<f64> {
+    let (digits, radix) = if let Some(digits) = value.strip_prefix("0x").or(value.strip_prefix("0X")) {
+        (digits, 16)
+    } else if let Some(digits) = value.strip_prefix("0b").or(value.strip_prefix("0B")) {
+        (digits, 2)
+    } else if let Some(digits) = value.strip_prefix("0o").or(value.strip_prefix("0O")) {
+        (digits, 8)
+    } else {
+        return value.parse().ok();
+    };
+
+    u64::from_str_radix(digits, radix).ok().map(|n| n as f64)
+}
+
 #[derive(Debug)]
 pub struct Parser<'a> {
     lexer: &'a Lexer,
     curr_token: Option<&'a Token>,
     curr_token_pos: usize,
+    // location of the last token seen, used to locate errors at EOF
+    last_loc: Loc,
 }
 
 impl<'a> Parser<'a> {
@@ -27,59 +168,179 @@ impl<'a> Parser<'a> {
             lexer,
             curr_token: None,
             curr_token_pos: 0,
+            last_loc: Loc { line: 1, column: 1 },
         }
     }
 
-    pub fn e(&mut self) -> Result<(), String> {
-        self.t()?;
-        self.eline()?;
+    pub fn e(&mut self) -> Result<Box<Expr>, ParseError> {
+        self.advance();
+
+        let expr = self.parse_expr(0)?;
+
+        if let Some(token) = self.curr_token {
+            return Err(ParseError {
+                loc: token.loc,
+                message: format!("token Operator or EOF expected, received: {:#?}", token),
+            });
+        }
 
-        Ok(())
+        Ok(expr)
     }
 
-    fn eline(&mut self) -> Result<(), String> {
+    fn advance(&mut self) {
         self.curr_token = self.lexer.advance(self.curr_token_pos);
         self.curr_token_pos += 1;
 
         if let Some(token) = self.curr_token {
-            let token_t = token.get_type();
+            self.last_loc = token.loc;
+        }
+    }
 
-            if token_t != Symbol::Operator {
-                self.op(token_t)?;
-                self.t()?;
-                self.eline()?;
-            }
+    fn eof_err(&self, message: &str) -> ParseError {
+        ParseError {
+            loc: self.last_loc,
+            message: format!("{}, received: EOF", message),
         }
+    }
 
-        Ok(())
+    // binding power of each operator: (left, right), higher binds tighter;
+    // right > left makes the operator left-associative
+    fn binding_power(op: Op) -> (u8, u8) {
+        match op {
+            Op::Add | Op::Sub => (10, 11),
+            Op::Mul | Op::Div => (20, 21),
+        }
     }
 
-    fn t(&mut self) -> Result<(), String> {
-        self.curr_token = self.lexer.advance(self.curr_token_pos);
-        self.curr_token_pos += 1;
+    fn parse_expr(&mut self, min_bp: u8) -> Result<Box<Expr>, ParseError> {
+        let mut lhs = self.atom()?;
 
-        if let Some(token) = self.curr_token {
-            let token_t = token.get_type();
+        loop {
+            let op = match self.curr_token {
+                Some(token) if token.get_type() == Symbol::Operator => Op::from_token(token)?,
+                _ => break,
+            };
+
+            let (left_bp, right_bp) = Parser::binding_power(op);
 
-            if token_t != Symbol::Identifier && token_t != Symbol::Number {
-                return Err(format!(
-                    "Parser: token Identifier or Number expected, received: \n\n```\n{:#?}\n```",
-                    self.curr_token.unwrap()
-                ));
+            if left_bp < min_bp {
+                break;
             }
+
+            self.advance();
+
+            let rhs = self.parse_expr(right_bp)?;
+
+            lhs = Box::new(Expr::BinOp { op, lhs, rhs });
         }
 
-        Ok(())
+        Ok(lhs)
     }
 
-    fn op(&self, token_t: Symbol) -> Result<(), String> {
-        if token_t != Symbol::Operator {
-            return Err(format!(
-                "Parser: token Operator expected, received: \n\n```\n{:#?}\n```",
-                self.curr_token.unwrap()
-            ));
+    fn atom(&mut self) -> Result<Box<Expr>, ParseError> {
+        let token = self
+            .curr_token
+            .ok_or_else(|| self.eof_err("token Identifier, Number or '(' expected"))?;
+
+        // prefix `+`/`-`, e.g. `-5` or `-(1 + 2)`: binds tighter than any
+        // binary operator, so it's handled as part of the atom itself
+        if token.get_type() == Symbol::Operator && matches!(token.value.as_str(), "+" | "-") {
+            let op = Op::from_token(token)?;
+
+            self.advance();
+
+            let expr = self.atom()?;
+
+            return Ok(Box::new(Expr::UnaryOp { op, expr }));
+        }
+
+        if token.get_type() == Symbol::LParen {
+            self.advance();
+
+            let inner = self.parse_expr(0)?;
+
+            match self.curr_token {
+                Some(token) if token.get_type() == Symbol::RParen => (),
+                Some(token) => {
+                    return Err(ParseError {
+                        loc: token.loc,
+                        message: format!("token ')' expected, received: {:#?}", token),
+                    })
+                }
+                None => return Err(self.eof_err("token ')' expected")),
+            }
+
+            self.advance();
+
+            return Ok(inner);
         }
 
-        Ok(())
+        let expr = match token.get_type() {
+            Symbol::Number => Expr::Number(parse_number(&token.value).ok_or_else(|| ParseError {
+                loc: token.loc,
+                message: format!("malformed number literal, received: {:#?}", token),
+            })?),
+            Symbol::Float => Expr::Number(token.value.parse().map_err(|_| ParseError {
+                loc: token.loc,
+                message: format!("malformed number literal, received: {:#?}", token),
+            })?),
+            Symbol::Identifier => Expr::Ident(token.value.clone()),
+            Symbol::String => Expr::Str(token.value.clone()),
+            _ => {
+                return Err(ParseError {
+                    loc: token.loc,
+                    message: format!(
+                        "token Identifier, Number or '(' expected, received: {:#?}",
+                        token
+                    ),
+                })
+            }
+        };
+
+        self.advance();
+
+        Ok(Box::new(expr))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn eval_str(src: &str) -> f64 {
+        let mut lexer = Lexer::new();
+
+        lexer.scanner_str(src);
+
+        Parser::new(&lexer).e().unwrap().eval()
+    }
+
+    #[test]
+    fn mul_binds_tighter_than_add() {
+        assert_eq!(eval_str("2 + 3 * 4"), 14.0);
+    }
+
+    #[test]
+    fn parens_override_precedence() {
+        assert_eq!(eval_str("(2 + 3) * 4"), 20.0);
+    }
+
+    #[test]
+    fn subtraction_is_left_associative() {
+        assert_eq!(eval_str("10 - 3 - 2"), 5.0);
+    }
+
+    #[test]
+    fn unary_minus_binds_tighter_than_binary_operators() {
+        assert_eq!(eval_str("-2 * 3"), -6.0);
+    }
+
+    #[test]
+    fn trailing_garbage_after_a_valid_expression_is_an_error() {
+        let mut lexer = Lexer::new();
+
+        lexer.scanner_str("1 2 3");
+
+        assert!(Parser::new(&lexer).e().is_err());
     }
 }